@@ -5,19 +5,25 @@
 
 use std::error::Error;
 use std::fmt::Display;
+use std::fs;
 use std::io;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 /// Handles incorrect command usage
 #[derive(thiserror::Error, Debug)]
 pub enum FireplaceError {
     #[error(transparent)]
     IO(#[from] io::Error),
-    #[error("Invalid part, please use 1 or 2 as argument for --part flag.")]
+    #[error("Invalid part, please use 1, 2 or all as argument for --part flag.")]
     InvalidPart,
-    #[error("Missing part, please use 1 or 2 as argument for --part flag.")]
+    #[error("Missing part, please use 1, 2 or all as argument for --part flag.")]
     MissingPart,
+    #[error("Invalid format, please use \"text\" or \"json\" as argument for --format flag.")]
+    InvalidFormat,
+    #[error("--expect requires a single --part (1 or 2), not \"all\".")]
+    ExpectRequiresSinglePart,
     #[error("{0}")]
     FromUser(String),
 }
@@ -28,6 +34,7 @@ pub type FireplaceResult<T> = Result<T, FireplaceError>;
 enum AoCPart {
     Pt1,
     Pt2,
+    All,
 }
 
 impl FromStr for AoCPart {
@@ -37,11 +44,214 @@ impl FromStr for AoCPart {
         match s {
             "1" => Ok(Self::Pt1),
             "2" => Ok(Self::Pt2),
+            "all" => Ok(Self::All),
             _ => Err(FireplaceError::InvalidPart),
         }
     }
 }
 
+/// Output format used to print the solution's answer to stdout
+enum OutputFormat {
+    Text,
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = FireplaceError;
+
+    fn from_str(s: &str) -> FireplaceResult<Self> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err(FireplaceError::InvalidFormat),
+        }
+    }
+}
+
+/// Machine-readable record emitted in `--format json` mode, consumed by the
+/// `esb` orchestrator instead of scraping the text output.
+#[derive(serde::Serialize)]
+#[serde(tag = "status", rename_all = "lowercase")]
+enum FireplaceRecord {
+    Ok {
+        part: u8,
+        answer: String,
+        runtime_ns: u128,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        stats: Option<BenchStatsRecord>,
+    },
+    Error {
+        message: String,
+    },
+}
+
+/// The `--time` distribution, serialized alongside `runtime_ns` so `--format
+/// json` doesn't drop min/median/p95/p99/stddev down to a single mean.
+#[derive(serde::Serialize)]
+struct BenchStatsRecord {
+    runs: usize,
+    min_ns: u128,
+    mean_ns: u128,
+    median_ns: u128,
+    p95_ns: u128,
+    p99_ns: u128,
+    stddev_ns: u128,
+}
+
+impl From<&BenchStats> for BenchStatsRecord {
+    fn from(stats: &BenchStats) -> Self {
+        Self {
+            runs: stats.runs,
+            min_ns: stats.min.as_nanos(),
+            mean_ns: stats.mean.as_nanos(),
+            median_ns: stats.median.as_nanos(),
+            p95_ns: stats.p95.as_nanos(),
+            p99_ns: stats.p99.as_nanos(),
+            stddev_ns: stats.stddev.as_nanos(),
+        }
+    }
+}
+
+/// Number of untimed iterations run before sampling starts, so JIT/cache
+/// warmup isn't charged to the measured solution.
+const WARMUP_ITERS: usize = 3;
+/// When `--runs` isn't given, keep sampling until this much wall time has
+/// been spent, rather than forcing the caller to guess a sample count.
+const DEFAULT_TARGET_WALL_TIME: Duration = Duration::from_secs(1);
+/// Backstop so a solution that is unexpectedly fast doesn't spin forever
+/// trying to fill `DEFAULT_TARGET_WALL_TIME`.
+const MAX_AUTO_RUNS: usize = 10_000;
+
+/// Summary statistics over a `--time` benchmarking run.
+struct BenchStats {
+    runs: usize,
+    min: Duration,
+    mean: Duration,
+    median: Duration,
+    p95: Duration,
+    p99: Duration,
+    stddev: Duration,
+}
+
+impl BenchStats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let runs = samples.len();
+        let min = samples[0];
+        let mean = samples.iter().sum::<Duration>() / runs as u32;
+        let median = Self::percentile(&samples, 0.50);
+        let p95 = Self::percentile(&samples, 0.95);
+        let p99 = Self::percentile(&samples, 0.99);
+        let variance = samples
+            .iter()
+            .map(|d| {
+                let diff = d.as_secs_f64() - mean.as_secs_f64();
+                diff * diff
+            })
+            .sum::<f64>()
+            / runs as f64;
+        let stddev = Duration::from_secs_f64(variance.sqrt());
+
+        Self {
+            runs,
+            min,
+            mean,
+            median,
+            p95,
+            p99,
+            stddev,
+        }
+    }
+
+    fn percentile(sorted_samples: &[Duration], p: f64) -> Duration {
+        let idx = (((sorted_samples.len() - 1) as f64) * p).round() as usize;
+        sorted_samples[idx]
+    }
+}
+
+impl Display for BenchStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "RT {} runs", self.runs)?;
+        writeln!(f, "RT min {} ns", self.min.as_nanos())?;
+        writeln!(f, "RT mean {} ns", self.mean.as_nanos())?;
+        writeln!(f, "RT median {} ns", self.median.as_nanos())?;
+        writeln!(f, "RT p95 {} ns", self.p95.as_nanos())?;
+        writeln!(f, "RT p99 {} ns", self.p99.as_nanos())?;
+        write!(f, "RT stddev {} ns", self.stddev.as_nanos())
+    }
+}
+
+/// Either a single sample (the default, one-shot run) or the distribution
+/// collected under `--time`.
+enum Timing {
+    Single(Duration),
+    Bench(BenchStats),
+}
+
+impl Timing {
+    /// The single duration value reported in `--format json` mode. Benchmark
+    /// runs report their mean, since there's no single "the" sample.
+    fn representative_ns(&self) -> u128 {
+        match self {
+            Self::Single(d) => d.as_nanos(),
+            Self::Bench(stats) => stats.mean.as_nanos(),
+        }
+    }
+}
+
+impl Display for Timing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Single(d) => write!(f, "RT {} ns", d.as_nanos()),
+            Self::Bench(stats) => write!(f, "{}", stats),
+        }
+    }
+}
+
+/// Runs `f` a number of times, discarding a short warmup, and returns the
+/// last computed answer alongside the timed samples. `f` must be a pure
+/// `Fn` so repeated calls are sound; this is why `run` only takes this path
+/// behind the explicit `--time` flag.
+fn benchmark<T, E: Error>(
+    f: impl Fn() -> Result<T, E>,
+    runs: Option<usize>,
+) -> (FireplaceResult<T>, Vec<Duration>) {
+    for _ in 0..WARMUP_ITERS {
+        let _ = f();
+    }
+
+    let mut samples = Vec::new();
+    let mut last = None;
+    match runs {
+        Some(n) => {
+            for _ in 0..n.max(1) {
+                let start = Instant::now();
+                let result = f();
+                samples.push(start.elapsed());
+                last = Some(result);
+            }
+        }
+        None => {
+            let mut elapsed_total = Duration::ZERO;
+            while samples.len() < MAX_AUTO_RUNS
+                && (samples.is_empty() || elapsed_total < DEFAULT_TARGET_WALL_TIME)
+            {
+                let start = Instant::now();
+                let result = f();
+                let sample = start.elapsed();
+                elapsed_total += sample;
+                samples.push(sample);
+                last = Some(result);
+            }
+        }
+    }
+
+    let answer = last
+        .expect("benchmark always performs at least one timed run")
+        .map_err(|e| FireplaceError::FromUser(e.to_string()));
+    (answer, samples)
+}
+
 trait InputReader {
     fn load_fireplace_input(&mut self) -> FireplaceResult<String>;
 }
@@ -53,8 +263,52 @@ impl InputReader for io::Stdin {
     }
 }
 
+/// Conventional directory holding named example fixtures, resolved as
+/// `{EXAMPLES_DIR}/{name}.txt` by the `--example` flag.
+const EXAMPLES_DIR: &str = "data/examples";
+
+/// Selects where the puzzle input comes from: stdin (the default, piped in
+/// by `esb`), a file on disk (`--input`), or a named fixture under
+/// [`EXAMPLES_DIR`] (`--example`).
+enum InputSource {
+    Stdin(io::Stdin),
+    File(PathBuf),
+    Example(String),
+}
+
+impl InputReader for InputSource {
+    fn load_fireplace_input(&mut self) -> FireplaceResult<String> {
+        match self {
+            Self::Stdin(stdin) => stdin.load_fireplace_input(),
+            Self::File(path) => Ok(fs::read_to_string(path)?),
+            Self::Example(name) => {
+                Ok(fs::read_to_string(Path::new(EXAMPLES_DIR).join(format!("{name}.txt")))?)
+            }
+        }
+    }
+}
+
+impl TryFrom<&clap::ArgMatches> for InputSource {
+    type Error = FireplaceError;
+
+    fn try_from(matches: &clap::ArgMatches) -> Result<Self, Self::Error> {
+        match (
+            matches.get_one::<String>("input"),
+            matches.get_one::<String>("example"),
+        ) {
+            (Some(path), _) => Ok(Self::File(PathBuf::from(path))),
+            (None, Some(name)) => Ok(Self::Example(name.clone())),
+            (None, None) => Ok(Self::Stdin(io::stdin())),
+        }
+    }
+}
+
 struct FireplaceArgs {
     part: AoCPart,
+    format: OutputFormat,
+    time: bool,
+    runs: Option<usize>,
+    expect: Option<String>,
     args: Vec<String>,
 }
 
@@ -66,13 +320,31 @@ impl TryFrom<clap::ArgMatches> for FireplaceArgs {
             return Err(FireplaceError::MissingPart);
         };
         let part = part.parse::<AoCPart>()?;
+        let format = matches
+            .get_one::<String>("format")
+            .map(|f| f.parse::<OutputFormat>())
+            .transpose()?
+            .unwrap_or(OutputFormat::Text);
+        let time = matches.get_flag("time");
+        let runs = matches.get_one::<usize>("runs").copied();
+        let expect = matches.get_one::<String>("expect").cloned();
+        if expect.is_some() && matches!(part, AoCPart::All) {
+            return Err(FireplaceError::ExpectRequiresSinglePart);
+        }
         let args: Vec<String> = matches
             .get_many::<String>("args")
             .unwrap_or_default()
             .map(|v| v.into())
             .collect();
 
-        Ok(Self { part, args })
+        Ok(Self {
+            part,
+            format,
+            time,
+            runs,
+            expect,
+            args,
+        })
     }
 }
 
@@ -83,10 +355,51 @@ fn parser() -> clap::Command {
             clap::Arg::new("part")
                 .short('p')
                 .long("part")
-                .help("Run solution part 1 or part 2")
-                .value_parser(["1", "2"])
+                .help("Run solution part 1, part 2, or \"all\" to run both in one invocation")
+                .value_parser(["1", "2", "all"])
                 .required(true),
         )
+        .arg(
+            clap::Arg::new("format")
+                .short('f')
+                .long("format")
+                .help("Output format for the answer, \"text\" for humans or \"json\" for tooling like `esb` to consume")
+                .value_parser(["text", "json"])
+                .default_value("text"),
+        )
+        .arg(
+            clap::Arg::new("time")
+                .short('t')
+                .long("time")
+                .help("Benchmark the solution over multiple runs instead of timing a single call")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            clap::Arg::new("runs")
+                .long("runs")
+                .help("Number of timed iterations to collect with --time (default: auto-scale to ~1s of timed runs, at most 10000 runs)")
+                .value_parser(clap::value_parser!(usize))
+                .requires("time"),
+        )
+        .arg(
+            clap::Arg::new("input")
+                .short('i')
+                .long("input")
+                .help("Read the puzzle input from this file instead of stdin")
+                .conflicts_with("example"),
+        )
+        .arg(
+            clap::Arg::new("example")
+                .short('e')
+                .long("example")
+                .help("Read a named example fixture from data/examples/<name>.txt instead of stdin")
+                .conflicts_with("input"),
+        )
+        .arg(
+            clap::Arg::new("expect")
+                .long("expect")
+                .help("Assert the computed answer equals this value, exiting non-zero with a diff on mismatch"),
+        )
         .arg(
             clap::Arg::new("args")
                 .short('a')
@@ -111,11 +424,105 @@ impl<T: Display, U: Display> Display for Either<T, U> {
     }
 }
 
+/// Return value for `run`: either a single part was selected, or `--part
+/// all` was, and both solutions ran against the same loaded input.
+pub enum PartsResult<T, U> {
+    Single(Either<T, U>),
+    Both(T, U),
+}
+
+impl<T: Display, U: Display> Display for PartsResult<T, U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Single(answer) => write!(f, "{}", answer),
+            Self::Both(t, u) => write!(f, "{}\n{}", t, u),
+        }
+    }
+}
+
+/// Times (or benchmarks, under `--time`) a single solve call.
+fn execute<R, E: Error>(
+    f: impl Fn() -> Result<R, E>,
+    time: bool,
+    runs: Option<usize>,
+) -> (FireplaceResult<R>, Timing) {
+    if time {
+        let (answer, samples) = benchmark(f, runs);
+        (answer, Timing::Bench(BenchStats::from_samples(samples)))
+    } else {
+        let start = Instant::now();
+        let answer = f().map_err(|e| FireplaceError::FromUser(e.to_string()));
+        (answer, Timing::Single(start.elapsed()))
+    }
+}
+
+/// Prints a successfully computed answer in the requested format.
+fn print_ok(answer: &impl Display, part: u8, timing: &Timing, format: &OutputFormat) {
+    match format {
+        OutputFormat::Text => {
+            println!("{}", answer);
+            println!("{}", timing);
+        }
+        OutputFormat::Json => {
+            let stats = match timing {
+                Timing::Single(_) => None,
+                Timing::Bench(stats) => Some(BenchStatsRecord::from(stats)),
+            };
+            let record = FireplaceRecord::Ok {
+                part,
+                answer: answer.to_string(),
+                runtime_ns: timing.representative_ns(),
+                stats,
+            };
+            println!(
+                "{}",
+                serde_json::to_string(&record).expect("FireplaceRecord serialization is infallible")
+            );
+        }
+    }
+}
+
+/// Compares the computed answer against `--expect`, returning a diff-style
+/// `FireplaceError::FromUser` on a mismatch. Doesn't print anything itself —
+/// callers print the pass marker only after the answer has been printed, so
+/// output stays answer-then-confirmation.
+fn check_expect(answer: &impl Display, expected: &str) -> FireplaceResult<()> {
+    let actual = answer.to_string();
+    if actual == expected {
+        return Ok(());
+    }
+    Err(FireplaceError::FromUser(format!(
+        "Expected answer did not match.\n- expected: {expected}\n+ actual:   {actual}"
+    )))
+}
+
+/// Prints the `--expect` pass marker in text mode, once the answer has
+/// already been printed by `print_ok`.
+fn print_expect_pass(format: &OutputFormat) {
+    if matches!(format, OutputFormat::Text) {
+        println!("PASS");
+    }
+}
+
+/// Prints a `FireplaceError::FromUser` failure as an error record in `--format
+/// json` mode. Prints nothing in text mode or for other error variants.
+fn print_error(e: &FireplaceError, format: &OutputFormat) {
+    if let (OutputFormat::Json, FireplaceError::FromUser(_)) = (format, e) {
+        let record = FireplaceRecord::Error {
+            message: e.to_string(),
+        };
+        println!(
+            "{}",
+            serde_json::to_string(&record).expect("FireplaceRecord serialization is infallible")
+        );
+    }
+}
+
 /// Runs the solution functions in compliance with FIREPLACEv1 protocol
 pub fn v1_run<T, E1, U, E2>(
     solve_pt1: impl Fn(&str, Vec<String>) -> Result<T, E1>,
     solve_pt2: impl Fn(&str, Vec<String>) -> Result<U, E2>,
-) -> FireplaceResult<Either<T, U>>
+) -> FireplaceResult<PartsResult<T, U>>
 where
     T: Display + 'static,
     U: Display + 'static,
@@ -123,8 +530,9 @@ where
     E2: Error,
 {
     let parser_matches = parser().get_matches();
+    let input_reader = InputSource::try_from(&parser_matches)?;
     let fp_args = FireplaceArgs::try_from(parser_matches)?;
-    run(&solve_pt1, &solve_pt2, io::stdin(), fp_args)
+    run(&solve_pt1, &solve_pt2, input_reader, fp_args)
 }
 
 fn run<T, E1, U, E2>(
@@ -132,7 +540,7 @@ fn run<T, E1, U, E2>(
     solve_pt2: impl Fn(&str, Vec<String>) -> Result<U, E2>,
     mut input_reader: impl InputReader,
     fp_args: FireplaceArgs,
-) -> FireplaceResult<Either<T, U>>
+) -> FireplaceResult<PartsResult<T, U>>
 where
     T: Display + 'static,
     U: Display + 'static,
@@ -140,30 +548,91 @@ where
     E2: Error,
 {
     let input_data = input_reader.load_fireplace_input()?;
-    let start = Instant::now();
-    let answer = match fp_args.part {
-        AoCPart::Pt1 => solve_pt1(&input_data, fp_args.args)
-            .map_err(|e| FireplaceError::FromUser(e.to_string()))
-            .map(Either::Part1),
-        AoCPart::Pt2 => solve_pt2(&input_data, fp_args.args)
-            .map_err(|e| FireplaceError::FromUser(e.to_string()))
-            .map(Either::Part2),
-    };
-    let duration = start.elapsed();
-
-    match answer {
-        Ok(answer) => {
-            println!("{}", answer);
-            println!("RT {} ns", duration.as_nanos());
-            Ok(answer)
+    let FireplaceArgs {
+        part,
+        format,
+        time,
+        runs,
+        expect,
+        args,
+    } = fp_args;
+
+    match part {
+        AoCPart::Pt1 => {
+            let (answer, timing) = execute(|| solve_pt1(&input_data, args.clone()), time, runs);
+            match answer {
+                Ok(answer) => {
+                    if let Some(expected) = &expect {
+                        if let Err(e) = check_expect(&answer, expected) {
+                            print_error(&e, &format);
+                            return Err(e);
+                        }
+                    }
+                    print_ok(&answer, 1, &timing, &format);
+                    if expect.is_some() {
+                        print_expect_pass(&format);
+                    }
+                    Ok(PartsResult::Single(Either::Part1(answer)))
+                }
+                Err(e) => {
+                    print_error(&e, &format);
+                    Err(e)
+                }
+            }
+        }
+        AoCPart::Pt2 => {
+            let (answer, timing) = execute(|| solve_pt2(&input_data, args.clone()), time, runs);
+            match answer {
+                Ok(answer) => {
+                    if let Some(expected) = &expect {
+                        if let Err(e) = check_expect(&answer, expected) {
+                            print_error(&e, &format);
+                            return Err(e);
+                        }
+                    }
+                    print_ok(&answer, 2, &timing, &format);
+                    if expect.is_some() {
+                        print_expect_pass(&format);
+                    }
+                    Ok(PartsResult::Single(Either::Part2(answer)))
+                }
+                Err(e) => {
+                    print_error(&e, &format);
+                    Err(e)
+                }
+            }
+        }
+        AoCPart::All => {
+            let (answer1, timing1) = execute(|| solve_pt1(&input_data, args.clone()), time, runs);
+            let answer1 = match answer1 {
+                Ok(answer1) => answer1,
+                Err(e) => {
+                    print_error(&e, &format);
+                    return Err(e);
+                }
+            };
+            print_ok(&answer1, 1, &timing1, &format);
+
+            let (answer2, timing2) = execute(|| solve_pt2(&input_data, args.clone()), time, runs);
+            let answer2 = match answer2 {
+                Ok(answer2) => answer2,
+                Err(e) => {
+                    print_error(&e, &format);
+                    return Err(e);
+                }
+            };
+            print_ok(&answer2, 2, &timing2, &format);
+
+            Ok(PartsResult::Both(answer1, answer2))
         }
-        Err(e) => Err(e),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
 
     struct TestInputReader;
     impl InputReader for TestInputReader {
@@ -192,6 +661,10 @@ mod tests {
     fn test_calls_solve_pt1() {
         let fp_args = FireplaceArgs {
             part: AoCPart::Pt1,
+            format: OutputFormat::Text,
+            time: false,
+            runs: None,
+            expect: None,
             args: vec![],
         };
         let answer = test_runner(fp_args).unwrap();
@@ -203,6 +676,10 @@ mod tests {
     fn test_calls_solve_pt1_with_args() {
         let fp_args = FireplaceArgs {
             part: AoCPart::Pt1,
+            format: OutputFormat::Text,
+            time: false,
+            runs: None,
+            expect: None,
             args: vec!["a".into(), "b".into(), "c".into()],
         };
         let answer = test_runner(fp_args).unwrap();
@@ -214,12 +691,178 @@ mod tests {
     fn test_calls_solve_pt2() {
         let fp_args = FireplaceArgs {
             part: AoCPart::Pt2,
+            format: OutputFormat::Text,
+            time: false,
+            runs: None,
+            expect: None,
             args: vec![],
         };
         let answer = test_runner(fp_args).unwrap();
         assert_eq!(answer.to_string(), PT2_RETURN);
     }
 
+    #[test]
+    fn test_json_format_returns_answer() {
+        let fp_args = FireplaceArgs {
+            part: AoCPart::Pt1,
+            format: OutputFormat::Json,
+            time: false,
+            runs: None,
+            expect: None,
+            args: vec![],
+        };
+        let answer = test_runner(fp_args).unwrap();
+        assert_eq!(answer.to_string(), "sample input");
+    }
+
+    #[test]
+    fn test_time_with_json_format_includes_full_distribution() {
+        let (_, timing) = execute(|| solve_pt1("sample input", vec![]), true, Some(5));
+        let stats = match &timing {
+            Timing::Bench(stats) => BenchStatsRecord::from(stats),
+            Timing::Single(_) => panic!("--time must produce a Timing::Bench"),
+        };
+        let record = FireplaceRecord::Ok {
+            part: 1,
+            answer: "sample input".into(),
+            runtime_ns: timing.representative_ns(),
+            stats: Some(stats),
+        };
+        let json = serde_json::to_string(&record).unwrap();
+        assert!(json.contains("\"min_ns\""));
+        assert!(json.contains("\"median_ns\""));
+        assert!(json.contains("\"p95_ns\""));
+        assert!(json.contains("\"p99_ns\""));
+        assert!(json.contains("\"stddev_ns\""));
+    }
+
+    #[test]
+    fn test_time_with_fixed_runs_returns_answer() {
+        let fp_args = FireplaceArgs {
+            part: AoCPart::Pt1,
+            format: OutputFormat::Text,
+            time: true,
+            runs: Some(5),
+            expect: None,
+            args: vec![],
+        };
+        let answer = test_runner(fp_args).unwrap();
+        assert_eq!(answer.to_string(), "sample input");
+    }
+
+    #[test]
+    fn test_benchmark_drives_the_closure_through_warmup_and_runs() {
+        let calls = Rc::new(Cell::new(0u32));
+        let counter = Rc::clone(&calls);
+        let f = move || -> FireplaceResult<&'static str> {
+            counter.set(counter.get() + 1);
+            Ok("sample input")
+        };
+
+        let (answer, samples) = benchmark(f, Some(5));
+
+        assert_eq!(answer.unwrap(), "sample input");
+        assert_eq!(samples.len(), 5);
+        assert_eq!(calls.get() as usize, WARMUP_ITERS + 5);
+    }
+
+    #[test]
+    fn test_part_all_runs_both_parts() {
+        let fp_args = FireplaceArgs {
+            part: AoCPart::All,
+            format: OutputFormat::Text,
+            time: false,
+            runs: None,
+            expect: None,
+            args: vec![],
+        };
+        let answer = test_runner(fp_args).unwrap();
+        assert_eq!(answer.to_string(), format!("sample input\n{}", PT2_RETURN));
+    }
+
+    #[test]
+    fn test_bench_stats_from_samples() {
+        let samples = vec![
+            Duration::from_nanos(10),
+            Duration::from_nanos(20),
+            Duration::from_nanos(30),
+            Duration::from_nanos(40),
+        ];
+        let stats = BenchStats::from_samples(samples);
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.mean, Duration::from_nanos(25));
+        assert_eq!(stats.median, Duration::from_nanos(30));
+        assert_eq!(stats.p95, Duration::from_nanos(40));
+        assert_eq!(stats.p99, Duration::from_nanos(40));
+    }
+
+    #[test]
+    fn test_file_input_source_reads_file_contents() {
+        let path = std::env::temp_dir().join("esb_fireplace_test_file_input.txt");
+        fs::write(&path, "file input").unwrap();
+
+        let mut reader = InputSource::File(path.clone());
+        let data = reader.load_fireplace_input().unwrap();
+
+        fs::remove_file(&path).unwrap();
+        assert_eq!(data, "file input");
+    }
+
+    #[test]
+    fn test_example_input_source_reads_named_fixture() {
+        let dir = Path::new(EXAMPLES_DIR);
+        let examples_dir_existed = dir.exists();
+        let root = Path::new("data");
+        let root_existed = root.exists();
+        fs::create_dir_all(dir).unwrap();
+        let path = dir.join("test_fixture.txt");
+        fs::write(&path, "example input").unwrap();
+
+        let mut reader = InputSource::Example("test_fixture".into());
+        let data = reader.load_fireplace_input().unwrap();
+
+        if examples_dir_existed {
+            fs::remove_file(&path).unwrap();
+        } else if root_existed {
+            fs::remove_dir_all(dir).unwrap();
+        } else {
+            fs::remove_dir_all(root).unwrap();
+        }
+        assert_eq!(data, "example input");
+    }
+
+    #[test]
+    fn test_expect_passes_on_matching_answer() {
+        let fp_args = FireplaceArgs {
+            part: AoCPart::Pt1,
+            format: OutputFormat::Text,
+            time: false,
+            runs: None,
+            expect: Some("sample input".into()),
+            args: vec![],
+        };
+        let answer = test_runner(fp_args).unwrap();
+        assert_eq!(answer.to_string(), "sample input");
+    }
+
+    #[test]
+    fn test_expect_errors_on_mismatched_answer() {
+        let fp_args = FireplaceArgs {
+            part: AoCPart::Pt1,
+            format: OutputFormat::Text,
+            time: false,
+            runs: None,
+            expect: Some("not the answer".into()),
+            args: vec![],
+        };
+        let result = test_runner(fp_args);
+        let Err(e) = result else {
+            panic!("Expected an error");
+        };
+        assert!(matches!(e, FireplaceError::FromUser(_)));
+    }
+
     #[test]
     // Check if the error is converted to a FireplaceError::FromUser
     fn test_error_conversion() {
@@ -227,6 +870,10 @@ mod tests {
             |_: &str, _: Vec<String>| -> Result<String, std::fmt::Error> { Err(std::fmt::Error) };
         let fp_args = FireplaceArgs {
             part: AoCPart::Pt1,
+            format: OutputFormat::Text,
+            time: false,
+            runs: None,
+            expect: None,
             args: vec![],
         };
         let result = super::run(some_aoc_function, solve_pt2, TestInputReader, fp_args);